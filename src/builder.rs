@@ -0,0 +1,83 @@
+//! A composable entry point for combining a custom mascot, eyes/tongue
+//! options, ANSI styling, and speech/thought mode in one render, instead of
+//! reaching for a separate single-purpose function per axis.
+
+use std::io::{self, Write};
+
+use crate::{balloon, Mascot, SayOptions, SpeechMode, Style};
+
+/// Builds up a balloon render from Ferris's defaults, overriding only the
+/// axes the caller needs.
+///
+/// ```
+/// use ferris_says::{SayBuilder, SayOptions};
+/// use std::io::Cursor;
+///
+/// let mut out = Cursor::new(Vec::new());
+/// SayBuilder::new("I'm dead.", 20)
+///     .think()
+///     .options(SayOptions::new().eyes(['x', 'x']))
+///     .write(&mut out)
+///     .unwrap();
+/// ```
+#[derive(Debug, Clone)]
+pub struct SayBuilder<'a> {
+    input: &'a str,
+    max_width: usize,
+    mode: SpeechMode,
+    mascot: Mascot,
+    options: SayOptions,
+    style: Style,
+}
+
+impl<'a> SayBuilder<'a> {
+    /// Starts from Ferris, in speech mode, with no custom options or
+    /// styling.
+    pub fn new(input: &'a str, max_width: usize) -> Self {
+        SayBuilder {
+            input,
+            max_width,
+            mode: SpeechMode::Say,
+            mascot: Mascot::ferris(),
+            options: SayOptions::default(),
+            style: Style::default(),
+        }
+    }
+
+    /// Renders a thought balloon instead of a speech balloon.
+    pub fn think(mut self) -> Self {
+        self.mode = SpeechMode::Think;
+        self
+    }
+
+    /// Renders `mascot` in place of the default Ferris art.
+    pub fn mascot(mut self, mascot: Mascot) -> Self {
+        self.mascot = mascot;
+        self
+    }
+
+    /// Overrides the mascot's eyes and tongue.
+    pub fn options(mut self, options: SayOptions) -> Self {
+        self.options = options;
+        self
+    }
+
+    /// Applies ANSI styling to the border, text, and mascot.
+    pub fn style(mut self, style: Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Renders the configured balloon to `writer`.
+    pub fn write<W: Write>(&self, writer: &mut W) -> io::Result<()> {
+        balloon(
+            &self.mascot,
+            self.input,
+            self.max_width,
+            self.mode,
+            &self.options,
+            &self.style,
+            writer,
+        )
+    }
+}
@@ -0,0 +1,184 @@
+//! Draw Ferris in a speech (or thought) balloon, `cowsay`-style.
+
+use std::io::{self, Write};
+
+mod builder;
+mod mascot;
+mod options;
+mod stream;
+mod style;
+mod width;
+mod wrap;
+
+pub use builder::SayBuilder;
+pub use mascot::Mascot;
+pub use options::SayOptions;
+pub use stream::say_stream;
+pub use style::{Color, Region, Style};
+use width::display_width;
+use wrap::wrap_text;
+
+/// Which balloon style to render.
+///
+/// `Say` mirrors `cowsay`'s speech balloon (`\` tether lines, `<...>`/`|...|`
+/// borders); `Think` mirrors `cowthink`'s thought balloon (a trail of `o`
+/// bubbles, `(...)` borders).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SpeechMode {
+    Say,
+    Think,
+}
+
+/// Draw Ferris saying `input`, wrapped to `max_width` columns, to `writer`.
+///
+/// For a custom mascot, eyes/tongue, ANSI styling, or thought-balloon mode,
+/// use [`SayBuilder`] instead — it composes all of those in one render.
+pub fn say<W: Write>(input: &str, max_width: usize, writer: &mut W) -> io::Result<()> {
+    SayBuilder::new(input, max_width).write(writer)
+}
+
+/// Draw Ferris thinking `input`, wrapped to `max_width` columns, to `writer`.
+///
+/// For a custom mascot, eyes/tongue, or ANSI styling alongside thought
+/// mode, use [`SayBuilder`] instead.
+pub fn think<W: Write>(input: &str, max_width: usize, writer: &mut W) -> io::Result<()> {
+    SayBuilder::new(input, max_width).think().write(writer)
+}
+
+pub(crate) fn balloon<W: Write>(
+    mascot: &Mascot,
+    input: &str,
+    max_width: usize,
+    mode: SpeechMode,
+    options: &SayOptions,
+    style: &Style,
+    writer: &mut W,
+) -> io::Result<()> {
+    let lines = wrap_text(input, max_width);
+    let width = lines.iter().map(|l| display_width(l)).max().unwrap_or(0);
+
+    write_border(width, style, writer)?;
+    write_body(&lines, width, mode, style, writer)?;
+    write_border(width, style, writer)?;
+
+    let tether = tether_text(mode);
+    let art = mascot.render(&tether, &options.eyes_str(), &options.tongue);
+    writer.write_all(style.apply(&style.mascot, &art).as_bytes())?;
+
+    Ok(())
+}
+
+fn write_border<W: Write>(width: usize, style: &Style, writer: &mut W) -> io::Result<()> {
+    let border = format!(" {}", "-".repeat(width + 2));
+    writeln!(writer, "{}", style.apply(&style.border, &border))
+}
+
+fn write_body<W: Write>(
+    lines: &[String],
+    width: usize,
+    mode: SpeechMode,
+    style: &Style,
+    writer: &mut W,
+) -> io::Result<()> {
+    let (left_single, right_single) = match mode {
+        SpeechMode::Say => ('<', '>'),
+        SpeechMode::Think => ('(', ')'),
+    };
+    let (left_first, right_first) = match mode {
+        SpeechMode::Say => ('/', '\\'),
+        SpeechMode::Think => ('(', ')'),
+    };
+    let (left_last, right_last) = match mode {
+        SpeechMode::Say => ('\\', '/'),
+        SpeechMode::Think => ('(', ')'),
+    };
+    let (left_mid, right_mid) = match mode {
+        SpeechMode::Say => ('|', '|'),
+        SpeechMode::Think => ('(', ')'),
+    };
+
+    let last = lines.len().saturating_sub(1);
+    for (i, line) in lines.iter().enumerate() {
+        let (left, right) = if lines.len() == 1 {
+            (left_single, right_single)
+        } else if i == 0 {
+            (left_first, right_first)
+        } else if i == last {
+            (left_last, right_last)
+        } else {
+            (left_mid, right_mid)
+        };
+
+        let padding = width - display_width(line);
+        let text = format!("{line}{}", " ".repeat(padding));
+        writeln!(
+            writer,
+            "{} {} {}",
+            style.apply(&style.border, &left.to_string()),
+            style.apply(&style.text, &text),
+            style.apply(&style.border, &right.to_string()),
+        )?;
+    }
+
+    Ok(())
+}
+
+/// The tether lines substituted into a mascot's `$thoughts` marker: `\`
+/// lines for a spoken balloon, a rising trail of `o` bubbles for a thought
+/// balloon.
+fn tether_text(mode: SpeechMode) -> String {
+    match mode {
+        SpeechMode::Say => "        \\\n         \\".to_string(),
+        SpeechMode::Think => "        o\n         o\n          o".to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Cursor;
+
+    fn rendered<F>(render: F) -> String
+    where
+        F: FnOnce(&mut Cursor<Vec<u8>>) -> io::Result<()>,
+    {
+        let mut buf = Cursor::new(Vec::new());
+        render(&mut buf).unwrap();
+        String::from_utf8(buf.into_inner()).unwrap()
+    }
+
+    #[test]
+    fn say_renders_the_speech_balloon_and_ferris_art() {
+        let output = rendered(|w| say("hi", 20, w));
+        assert_eq!(
+            output,
+            " ----\n< hi >\n ----\n        \\\n         \\\n\n    _~^~^~_\n\\) /  o o  \\ (/\n  '_   -   _'\n  / '-----' \\\n"
+        );
+    }
+
+    #[test]
+    fn think_renders_the_thought_balloon_and_ferris_art() {
+        let output = rendered(|w| think("hi", 20, w));
+        assert_eq!(
+            output,
+            " ----\n( hi )\n ----\n        o\n         o\n          o\n\n    _~^~^~_\n\\) /  o o  \\ (/\n  '_   -   _'\n  / '-----' \\\n"
+        );
+    }
+
+    #[test]
+    fn multi_line_body_uses_first_last_and_middle_delimiters() {
+        let output = rendered(|w| say("one two three four five six seven", 10, w));
+        let body: Vec<&str> = output
+            .lines()
+            .skip(1)
+            .take_while(|line| !line.chars().all(|c| c == ' ' || c == '-'))
+            .collect();
+        assert!(body.first().unwrap().starts_with('/') && body.first().unwrap().ends_with('\\'));
+        assert!(body.last().unwrap().starts_with('\\') && body.last().unwrap().ends_with('/'));
+        if body.len() > 2 {
+            for middle in &body[1..body.len() - 1] {
+                assert!(middle.starts_with('|') && middle.ends_with('|'));
+            }
+        }
+    }
+}
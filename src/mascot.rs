@@ -0,0 +1,112 @@
+//! Pluggable mascot art, in the spirit of cowsay's `.cow` files.
+
+use std::fs;
+use std::io;
+use std::path::Path;
+
+/// Token marking where the balloon's tether lines attach to the mascot art.
+const THOUGHTS_TOKEN: &str = "$thoughts";
+/// Token substituted with the mascot's eye characters.
+const EYES_TOKEN: &str = "$eyes";
+/// Token substituted with the mascot's tongue string.
+const TONGUE_TOKEN: &str = "$tongue";
+
+const FERRIS_TEMPLATE: &str =
+    "$thoughts\n\n    _~^~^~_\n\\) /  $eyes  \\ (/\n  '_   $tongue   _'\n  / '-----' \\\n";
+
+/// An ASCII-art character that can be rendered in place of the default
+/// Ferris mascot.
+///
+/// A mascot is just a small template: a `$thoughts` line marking where the
+/// balloon's tether attaches, plus `$eyes` and `$tongue` tokens for
+/// expression substitution. Load one from a file with [`Mascot::from_path`]
+/// or embed one with [`Mascot::from_template`].
+#[derive(Debug, Clone)]
+pub struct Mascot {
+    name: String,
+    template: String,
+}
+
+impl Mascot {
+    /// Parse a mascot from an in-memory template string.
+    pub fn from_template(name: impl Into<String>, template: impl Into<String>) -> Self {
+        Mascot {
+            name: name.into(),
+            template: template.into(),
+        }
+    }
+
+    /// Load a mascot template from a file on disk.
+    pub fn from_path(path: impl AsRef<Path>) -> io::Result<Self> {
+        let path = path.as_ref();
+        let template = fs::read_to_string(path)?;
+        let name = path
+            .file_stem()
+            .map(|stem| stem.to_string_lossy().into_owned())
+            .unwrap_or_else(|| "mascot".to_string());
+        Ok(Mascot::from_template(name, template))
+    }
+
+    /// The built-in Ferris mascot, used by [`crate::say`] and [`crate::think`]
+    /// when no other mascot is given.
+    pub fn ferris() -> Self {
+        Mascot::from_template("ferris", FERRIS_TEMPLATE)
+    }
+
+    /// The mascot's name (its file stem, when loaded from a path).
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Substitutes the tether, eyes, and tongue tokens, returning the
+    /// finished art ready to append after the balloon.
+    pub(crate) fn render(&self, tether: &str, eyes: &str, tongue: &str) -> String {
+        self.template
+            .replace(THOUGHTS_TOKEN, tether)
+            .replace(EYES_TOKEN, eyes)
+            .replace(TONGUE_TOKEN, tongue)
+    }
+}
+
+impl Default for Mascot {
+    fn default() -> Self {
+        Mascot::ferris()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn render_substitutes_all_three_tokens() {
+        let mascot = Mascot::from_template("test", "$thoughts/$eyes/$tongue");
+        assert_eq!(mascot.render("tether", "eyes", "tongue"), "tether/eyes/tongue");
+    }
+
+    #[test]
+    fn render_leaves_unrelated_text_untouched() {
+        let mascot = Mascot::from_template("test", "before $eyes after");
+        assert_eq!(mascot.render("t", "O O", "~"), "before O O after");
+    }
+
+    #[test]
+    fn ferris_template_substitutes_into_the_default_art() {
+        let ferris = Mascot::ferris();
+        let art = ferris.render("        \\\n         \\", "o o", "-");
+        assert_eq!(
+            art,
+            "        \\\n         \\\n\n    _~^~^~_\n\\) /  o o  \\ (/\n  '_   -   _'\n  / '-----' \\\n"
+        );
+    }
+
+    #[test]
+    fn name_defaults_to_file_stem_when_loaded_from_a_path() {
+        let stem = format!("ferris-says-mascot-test-{}", std::process::id());
+        let path = std::env::temp_dir().join(format!("{stem}.cow"));
+        fs::write(&path, "$eyes").unwrap();
+        let mascot = Mascot::from_path(&path);
+        fs::remove_file(&path).unwrap();
+        assert_eq!(mascot.unwrap().name(), stem);
+    }
+}
@@ -0,0 +1,76 @@
+//! Expression customization, mirroring cowsay's `-e`/`-T` flags.
+
+/// Customizes the eyes and tongue substituted into a mascot's art.
+///
+/// Builds on the defaults Ferris normally ships with (`o o` eyes, a plain
+/// `-` mouth), so callers only need to override what they want to change:
+///
+/// ```
+/// use ferris_says::SayOptions;
+///
+/// let dead = SayOptions::new().eyes(['x', 'x']);
+/// let stoned = SayOptions::new().eyes(['*', '*']).tongue("U");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SayOptions {
+    pub eyes: [char; 2],
+    pub tongue: String,
+}
+
+impl SayOptions {
+    /// Starts from Ferris's usual expression.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the two eye characters.
+    pub fn eyes(mut self, eyes: [char; 2]) -> Self {
+        self.eyes = eyes;
+        self
+    }
+
+    /// Sets the tongue string shown in the mouth.
+    pub fn tongue(mut self, tongue: impl Into<String>) -> Self {
+        self.tongue = tongue.into();
+        self
+    }
+
+    pub(crate) fn eyes_str(&self) -> String {
+        format!("{} {}", self.eyes[0], self.eyes[1])
+    }
+}
+
+impl Default for SayOptions {
+    fn default() -> Self {
+        SayOptions {
+            eyes: ['o', 'o'],
+            tongue: "-".to_string(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn defaults_match_ferris_usual_expression() {
+        let options = SayOptions::new();
+        assert_eq!(options.eyes, ['o', 'o']);
+        assert_eq!(options.tongue, "-");
+        assert_eq!(options.eyes_str(), "o o");
+    }
+
+    #[test]
+    fn eyes_and_tongue_override_independently() {
+        let options = SayOptions::new().eyes(['x', 'x']).tongue("U");
+        assert_eq!(options.eyes_str(), "x x");
+        assert_eq!(options.tongue, "U");
+    }
+
+    #[test]
+    fn eyes_str_keeps_eyes_space_separated() {
+        let options = SayOptions::new().eyes(['*', '*']);
+        assert_eq!(options.eyes_str(), "* *");
+    }
+}
@@ -0,0 +1,154 @@
+//! Streaming input mode: render a balloon per paragraph as input arrives,
+//! instead of requiring the whole message up front.
+
+use std::io::{self, BufRead, Write};
+
+use crate::say;
+
+/// Starting capacity for the paragraph buffer, reused across paragraphs so
+/// piping a large or continuous stream through `say` doesn't grow memory
+/// with the stream's total size.
+const BUFFER_CAPACITY: usize = 8 * 1024;
+
+/// Once the paragraph buffer grows this large, it's flushed anyway, even if
+/// no blank line or newline has been seen. This bounds memory on a stream
+/// that never delivers a delimiter at all (`cat huge-no-newline-file |
+/// fsays`, or any other endless/adversarial input) — the buffer is checked
+/// after every byte, not just at line boundaries, so a single "line" can't
+/// grow past this no matter how the input is chunked.
+const MAX_PARAGRAPH_LEN: usize = BUFFER_CAPACITY;
+
+/// Reads `reader` incrementally, rendering a completed balloon for each
+/// paragraph (delimited by a blank line, by EOF, or by hitting
+/// `MAX_PARAGRAPH_LEN`) to `writer`. Lines within a paragraph are joined
+/// with a space and re-wrapped to `max_width`, rather than kept as separate
+/// boxed lines.
+///
+/// This lets the crate act as a shell filter over live or oversized input,
+/// rather than requiring the caller to buffer the whole message first.
+pub fn say_stream<R: BufRead, W: Write>(
+    mut reader: R,
+    max_width: usize,
+    writer: &mut W,
+) -> io::Result<()> {
+    let mut paragraph: Vec<u8> = Vec::with_capacity(BUFFER_CAPACITY);
+    let mut pending_newlines = 0u32;
+
+    loop {
+        let consumed = {
+            let chunk = reader.fill_buf()?;
+            if chunk.is_empty() {
+                break;
+            }
+
+            for &byte in chunk {
+                if byte == b'\n' {
+                    pending_newlines += 1;
+                    continue;
+                }
+
+                if pending_newlines == 1 {
+                    if !paragraph.is_empty() {
+                        paragraph.push(b' ');
+                    }
+                } else if pending_newlines >= 2 && !paragraph.is_empty() {
+                    flush(&paragraph, max_width, writer)?;
+                    paragraph.clear();
+                }
+                pending_newlines = 0;
+
+                paragraph.push(byte);
+
+                if paragraph.len() >= MAX_PARAGRAPH_LEN {
+                    flush(&paragraph, max_width, writer)?;
+                    paragraph.clear();
+                }
+            }
+
+            chunk.len()
+        };
+        reader.consume(consumed);
+    }
+
+    if !paragraph.is_empty() {
+        flush(&paragraph, max_width, writer)?;
+    }
+
+    Ok(())
+}
+
+/// Renders `paragraph` as a balloon, skipping it if it's blank once
+/// trimmed. Lossily re-decodes the bytes, since a forced flush at
+/// `MAX_PARAGRAPH_LEN` can land mid-codepoint.
+fn flush<W: Write>(paragraph: &[u8], max_width: usize, writer: &mut W) -> io::Result<()> {
+    let text = String::from_utf8_lossy(paragraph);
+    let trimmed = text.trim();
+    if !trimmed.is_empty() {
+        say(trimmed, max_width, writer)?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::{BufReader, Cursor};
+
+    fn run(input: &str, max_width: usize) -> String {
+        let reader = BufReader::new(Cursor::new(input));
+        let mut out = Cursor::new(Vec::new());
+        say_stream(reader, max_width, &mut out).unwrap();
+        String::from_utf8(out.into_inner()).unwrap()
+    }
+
+    fn balloon_count(output: &str) -> usize {
+        output.matches("_~^~^~_").count()
+    }
+
+    #[test]
+    fn blank_line_flushes_separate_balloons() {
+        let output = run("Hello\n\nWorld\n", 40);
+        assert_eq!(balloon_count(&output), 2);
+        assert!(output.contains("Hello"));
+        assert!(output.contains("World"));
+    }
+
+    #[test]
+    fn eof_without_trailing_blank_line_still_flushes() {
+        let output = run("Hello", 40);
+        assert_eq!(balloon_count(&output), 1);
+        assert!(output.contains("Hello"));
+    }
+
+    #[test]
+    fn repeated_blank_lines_do_not_emit_empty_balloons() {
+        let output = run("\n\n\nHello\n\n\n", 40);
+        assert_eq!(balloon_count(&output), 1);
+    }
+
+    #[test]
+    fn multi_line_paragraph_is_joined_and_rewrapped() {
+        let output = run("line one here\nline two here\n\n", 40);
+        assert_eq!(balloon_count(&output), 1);
+        assert!(output.contains("line one here line two here"));
+    }
+
+    #[test]
+    fn oversized_paragraph_is_flushed_without_a_blank_line() {
+        // Mimics an endless `yes`-style stream: many short lines with no
+        // blank-line delimiter in sight.
+        let input = "y\n".repeat(MAX_PARAGRAPH_LEN * 3);
+        let output = run(&input, 40);
+        assert!(balloon_count(&output) >= 3);
+    }
+
+    #[test]
+    fn oversized_single_line_with_no_newline_is_still_flushed() {
+        // The adversarial case: a stream that never emits a delimiter at
+        // all, e.g. `cat huge-no-newline-file | fsays`. Without a
+        // byte-level cap this would grow the paragraph buffer forever.
+        let input = "x".repeat(MAX_PARAGRAPH_LEN * 3);
+        let output = run(&input, 40);
+        assert!(balloon_count(&output) >= 3);
+    }
+}
@@ -0,0 +1,164 @@
+//! Optional ANSI styling for the balloon border, message text, and mascot.
+
+/// A terminal foreground/background color, as a basic SGR color.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Color {
+    Black,
+    Red,
+    Green,
+    Yellow,
+    Blue,
+    Magenta,
+    Cyan,
+    White,
+}
+
+impl Color {
+    fn fg_code(self) -> u8 {
+        30 + self.base_code()
+    }
+
+    fn bg_code(self) -> u8 {
+        40 + self.base_code()
+    }
+
+    fn base_code(self) -> u8 {
+        match self {
+            Color::Black => 0,
+            Color::Red => 1,
+            Color::Green => 2,
+            Color::Yellow => 3,
+            Color::Blue => 4,
+            Color::Magenta => 5,
+            Color::Cyan => 6,
+            Color::White => 7,
+        }
+    }
+}
+
+/// Styling applied to one region of the output: the balloon border, the
+/// message text, or the mascot art.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Region {
+    pub foreground: Option<Color>,
+    pub background: Option<Color>,
+    pub bold: bool,
+    pub dim: bool,
+}
+
+impl Region {
+    fn sgr_params(&self) -> Vec<u8> {
+        let mut params = Vec::new();
+        if self.bold {
+            params.push(1);
+        }
+        if self.dim {
+            params.push(2);
+        }
+        if let Some(fg) = self.foreground {
+            params.push(fg.fg_code());
+        }
+        if let Some(bg) = self.background {
+            params.push(bg.bg_code());
+        }
+        params
+    }
+}
+
+/// Enables (or disables) ANSI styling, and configures the look of each
+/// region of the balloon.
+///
+/// Styling is off by default; call [`Style::enable`] to turn it on once the
+/// regions are configured. Plain output is unaffected when disabled.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Style {
+    pub enabled: bool,
+    pub border: Region,
+    pub text: Region,
+    pub mascot: Region,
+}
+
+impl Style {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Turns ANSI styling on.
+    pub fn enable(mut self) -> Self {
+        self.enabled = true;
+        self
+    }
+
+    /// Wraps `s` in the SGR codes for `region`, or returns it unchanged if
+    /// styling is disabled or the region has no styling configured.
+    pub(crate) fn apply(&self, region: &Region, s: &str) -> String {
+        if !self.enabled {
+            return s.to_string();
+        }
+
+        let params = region.sgr_params();
+        if params.is_empty() {
+            return s.to_string();
+        }
+
+        let codes = params
+            .iter()
+            .map(u8::to_string)
+            .collect::<Vec<_>>()
+            .join(";");
+        format!("\u{1b}[{codes}m{s}\u{1b}[0m")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn disabled_style_passes_text_through_unchanged() {
+        let style = Style::new();
+        let region = Region {
+            bold: true,
+            foreground: Some(Color::Red),
+            ..Region::default()
+        };
+        assert_eq!(style.apply(&region, "hi"), "hi");
+    }
+
+    #[test]
+    fn enabled_with_unconfigured_region_passes_text_through_unchanged() {
+        let style = Style::new().enable();
+        assert_eq!(style.apply(&Region::default(), "hi"), "hi");
+    }
+
+    #[test]
+    fn enabled_style_wraps_text_in_sgr_codes() {
+        let style = Style::new().enable();
+        let region = Region {
+            bold: true,
+            foreground: Some(Color::Red),
+            ..Region::default()
+        };
+        assert_eq!(style.apply(&region, "hi"), "\u{1b}[1;31mhi\u{1b}[0m");
+    }
+
+    #[test]
+    fn background_color_uses_the_40_series_code() {
+        let style = Style::new().enable();
+        let region = Region {
+            background: Some(Color::Blue),
+            ..Region::default()
+        };
+        assert_eq!(style.apply(&region, "hi"), "\u{1b}[44mhi\u{1b}[0m");
+    }
+
+    #[test]
+    fn dim_flag_emits_code_2() {
+        let style = Style::new().enable();
+        let region = Region {
+            dim: true,
+            ..Region::default()
+        };
+        assert_eq!(style.apply(&region, "hi"), "\u{1b}[2mhi\u{1b}[0m");
+    }
+}
@@ -0,0 +1,126 @@
+//! Display-column width computation, aware of CJK/wide characters and
+//! zero-width combining marks.
+//!
+//! This is a minimal East Asian Width table (the crate has no dependency on
+//! `unicode-width`): each character contributes `2` columns if it falls in
+//! one of the listed Wide/Fullwidth ranges (Hangul, CJK, Hiragana/Katakana,
+//! Yi, fullwidth forms), `0` if it falls in one of the listed combining-mark
+//! or zero-width ranges, and `1` otherwise. Wide characters outside these
+//! ranges, such as emoji, count as one column.
+
+/// The number of terminal columns a single character occupies.
+pub(crate) fn char_width(c: char) -> usize {
+    let cp = c as u32;
+
+    if is_zero_width(cp) {
+        0
+    } else if is_wide(cp) {
+        2
+    } else {
+        1
+    }
+}
+
+/// The total display width of `s` in terminal columns, skipping over any
+/// ANSI SGR escape sequences (`ESC [ ... m`) so styled output still lines up.
+pub(crate) fn display_width(s: &str) -> usize {
+    let mut width = 0;
+    let mut chars = s.chars();
+
+    while let Some(c) = chars.next() {
+        if c == '\u{1b}' {
+            for c in chars.by_ref() {
+                if c.is_ascii_alphabetic() {
+                    break;
+                }
+            }
+            continue;
+        }
+        width += char_width(c);
+    }
+
+    width
+}
+
+fn is_zero_width(cp: u32) -> bool {
+    matches!(cp,
+        0x0300..=0x036F   // combining diacritical marks
+        | 0x0483..=0x0489
+        | 0x0591..=0x05BD
+        | 0x0610..=0x061A
+        | 0x064B..=0x065F
+        | 0x06D6..=0x06DC
+        | 0x0E31 | 0x0E34..=0x0E3A | 0x0E47..=0x0E4E
+        | 0x200B..=0x200F // zero-width space/joiners, direction marks
+        | 0x202A..=0x202E
+        | 0x20D0..=0x20FF
+        | 0xFE00..=0xFE0F // variation selectors
+        | 0xFE20..=0xFE2F
+    )
+}
+
+fn is_wide(cp: u32) -> bool {
+    matches!(cp,
+        0x1100..=0x115F   // Hangul Jamo
+        | 0x2E80..=0x303E // CJK radicals, Kangxi, CJK symbols/punctuation
+        | 0x3041..=0x33FF // Hiragana, Katakana, CJK compatibility
+        | 0x3400..=0x4DBF // CJK unified ideographs extension A
+        | 0x4E00..=0x9FFF // CJK unified ideographs
+        | 0xA000..=0xA4CF // Yi syllables/radicals
+        | 0xAC00..=0xD7A3 // Hangul syllables
+        | 0xF900..=0xFAFF // CJK compatibility ideographs
+        | 0xFE30..=0xFE4F // CJK compatibility forms
+        | 0xFF00..=0xFF60 // fullwidth forms
+        | 0xFFE0..=0xFFE6 // fullwidth signs
+        | 0x20000..=0x3FFFD // supplementary CJK ideographic planes
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ascii_is_one_column_per_char() {
+        assert_eq!(display_width("Hello!"), 6);
+    }
+
+    #[test]
+    fn cjk_ideographs_are_two_columns_each() {
+        assert_eq!(display_width("你好"), 4);
+        assert_eq!(display_width("漢字"), 4);
+    }
+
+    #[test]
+    fn hiragana_and_katakana_are_wide() {
+        assert_eq!(char_width('あ'), 2);
+        assert_eq!(char_width('ア'), 2);
+    }
+
+    #[test]
+    fn hangul_syllables_are_wide() {
+        assert_eq!(char_width('안'), 2);
+    }
+
+    #[test]
+    fn combining_marks_are_zero_width() {
+        // 'e' + COMBINING ACUTE ACCENT
+        assert_eq!(display_width("e\u{0301}"), 1);
+        assert_eq!(char_width('\u{0301}'), 0);
+    }
+
+    #[test]
+    fn variation_selectors_are_zero_width() {
+        assert_eq!(char_width('\u{FE0F}'), 0);
+    }
+
+    #[test]
+    fn mixed_ascii_and_wide_text_sums_correctly() {
+        assert_eq!(display_width("ab你好cd"), 2 + 4 + 2);
+    }
+
+    #[test]
+    fn ansi_escape_sequences_do_not_count_towards_width() {
+        assert_eq!(display_width("\u{1b}[1;31mhi\u{1b}[0m"), 2);
+    }
+}
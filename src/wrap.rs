@@ -0,0 +1,49 @@
+//! Word-wrapping used to lay out the balloon body.
+//!
+//! Width is measured in display columns via [`crate::width`], so CJK/wide
+//! characters count for two columns and combining marks for zero, matching
+//! how a terminal actually lays the text out.
+
+use crate::width::display_width;
+
+/// Greedily wraps `input` into lines no wider than `max_width` display
+/// columns, breaking on whitespace and preserving existing newlines as
+/// paragraph breaks. Words are never split: a single word wider than
+/// `max_width` is kept whole on a line of its own, which is then wider than
+/// `max_width`.
+pub(crate) fn wrap_text(input: &str, max_width: usize) -> Vec<String> {
+    let max_width = max_width.max(1);
+    let mut lines = Vec::new();
+
+    for paragraph in input.split('\n') {
+        if paragraph.trim().is_empty() {
+            lines.push(String::new());
+            continue;
+        }
+
+        let mut current = String::new();
+        for word in paragraph.split_whitespace() {
+            let needed = if current.is_empty() {
+                display_width(word)
+            } else {
+                display_width(&current) + 1 + display_width(word)
+            };
+
+            if needed > max_width && !current.is_empty() {
+                lines.push(std::mem::take(&mut current));
+            }
+
+            if !current.is_empty() {
+                current.push(' ');
+            }
+            current.push_str(word);
+        }
+        lines.push(current);
+    }
+
+    if lines.is_empty() {
+        lines.push(String::new());
+    }
+
+    lines
+}